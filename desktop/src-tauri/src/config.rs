@@ -0,0 +1,53 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const CONFIG_FILE_NAME: &str = "config.json";
+const DEFAULT_PORT: u16 = 8080;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AppConfig {
+    pub backend_url: Option<String>,
+    pub generate_shortcut: Option<String>,
+}
+
+// Uses std::fs directly rather than tauri_plugin_fs: that plugin's API is
+// for scoped, capability-checked access from the frontend, not for a
+// command's own internal config file.
+fn config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(CONFIG_FILE_NAME))
+}
+
+pub fn load(app: &AppHandle) -> AppConfig {
+    let path = match config_path(app) {
+        Ok(path) => path,
+        Err(_) => return AppConfig::default(),
+    };
+
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(app: &AppHandle, config: &AppConfig) -> Result<(), String> {
+    let path = config_path(app)?;
+    let contents = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+pub fn resolve_backend_url(app: &AppHandle) -> String {
+    if let Some(url) = load(app).backend_url {
+        return url;
+    }
+
+    std::env::var("RESUMEAI_BACKEND_URL")
+        .unwrap_or_else(|_| format!("http://localhost:{}", DEFAULT_PORT))
+}
@@ -1,7 +1,47 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use serde::Serialize;
 use serde_json::json;
+use tauri::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+const DEFAULT_GENERATE_SHORTCUT: &str = "CmdOrCtrl+Shift+R";
+
+mod config;
+
+#[derive(Serialize)]
+struct UpdateInfo {
+    available: bool,
+    version: Option<String>,
+    current_version: Option<String>,
+    notes: Option<String>,
+}
+
+impl UpdateInfo {
+    fn up_to_date() -> Self {
+        Self {
+            available: false,
+            version: None,
+            current_version: None,
+            notes: None,
+        }
+    }
+
+    fn from_update(update: &Update) -> Self {
+        Self {
+            available: true,
+            version: Some(update.version.clone()),
+            current_version: Some(update.current_version.clone()),
+            notes: update.body.clone(),
+        }
+    }
+}
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -9,7 +49,8 @@ fn greet(name: &str) -> String {
 }
 
 #[tauri::command]
-async fn check_api_health(url: String) -> Result<bool, String> {
+async fn check_api_health(app: AppHandle, url: Option<String>) -> Result<bool, String> {
+    let url = url.unwrap_or_else(|| config::resolve_backend_url(&app));
     let client = reqwest::Client::new();
     match client.get(&url).send().await {
         Ok(response) => {
@@ -23,6 +64,18 @@ async fn check_api_health(url: String) -> Result<bool, String> {
     }
 }
 
+#[tauri::command]
+fn get_backend_url(app: AppHandle) -> String {
+    config::resolve_backend_url(&app)
+}
+
+#[tauri::command]
+fn set_backend_url(app: AppHandle, url: String) -> Result<(), String> {
+    let mut cfg = config::load(&app);
+    cfg.backend_url = Some(url);
+    config::save(&app, &cfg)
+}
+
 #[tauri::command]
 fn open_output_folder(path: String) -> Result<(), String> {
     #[cfg(target_os = "windows")]
@@ -53,24 +106,333 @@ fn open_output_folder(path: String) -> Result<(), String> {
 }
 
 #[tauri::command]
-fn get_platform_info() -> serde_json::Value {
+fn reveal_in_file_manager(file_path: String) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new("explorer")
+            .arg(format!("/select,{}", file_path))
+            .spawn()
+            .map_err(|e| e.to_string())?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg("-R")
+            .arg(&file_path)
+            .spawn()
+            .map_err(|e| e.to_string())?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let dbus_ok = std::process::Command::new("dbus-send")
+            .arg("--session")
+            .arg("--dest=org.freedesktop.FileManager1")
+            .arg("--type=method_call")
+            .arg("/org/freedesktop/FileManager1")
+            .arg("org.freedesktop.FileManager1.ShowItems")
+            .arg(format!("array:string:file://{}", file_path))
+            .arg("string:\"\"")
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false);
+
+        if !dbus_ok {
+            let parent = std::path::Path::new(&file_path)
+                .parent()
+                .map(|p| p.to_path_buf())
+                .unwrap_or_else(|| std::path::PathBuf::from(&file_path));
+            std::process::Command::new("xdg-open")
+                .arg(parent)
+                .spawn()
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+fn copy_resume_to_clipboard(app: AppHandle, path: String) -> Result<(), String> {
+    let bytes = std::fs::read(&path).map_err(|e| e.to_string())?;
+    let contents = String::from_utf8(bytes).unwrap_or(path);
+    app.clipboard()
+        .write_text(contents)
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn is_macos_accessibility_enabled() -> bool {
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXIsProcessTrusted() -> bool;
+    }
+
+    unsafe { AXIsProcessTrusted() }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn is_macos_accessibility_enabled() -> bool {
+    false
+}
+
+#[tauri::command]
+fn get_platform_info(app: AppHandle, output_dir: Option<String>) -> serde_json::Value {
+    let notifications_permitted = app
+        .notification()
+        .permission_state()
+        .map(|state| state == tauri_plugin_notification::PermissionState::Granted)
+        .unwrap_or(false);
+
+    let available_disk_space = output_dir.and_then(|dir| fs2::available_space(dir).ok());
+
     json!({
         "os": std::env::consts::OS,
         "arch": std::env::consts::ARCH,
         "family": std::env::consts::FAMILY,
+        "notificationsPermitted": notifications_permitted,
+        "availableDiskSpace": available_disk_space,
+        "macosAccessibilityEnabled": is_macos_accessibility_enabled(),
     })
 }
 
+#[tauri::command]
+fn notify_export_complete(
+    app: AppHandle,
+    title: String,
+    body: String,
+    file_path: String,
+) -> Result<(), String> {
+    app.notification()
+        .builder()
+        .title(title)
+        .body(format!("{body} ({file_path})"))
+        .show()
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn set_tray_status(app: AppHandle, generating: bool) -> Result<(), String> {
+    let tray = app.tray_by_id(TRAY_ICON_ID).ok_or("tray icon not found")?;
+    let tooltip = if generating {
+        "ResumeAI \u{2014} generating resume…"
+    } else {
+        "ResumeAI"
+    };
+    tray.set_tooltip(Some(tooltip)).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn check_for_update(app: AppHandle) -> Result<UpdateInfo, String> {
+    let updater = app.updater().map_err(|e| e.to_string())?;
+    match updater.check().await {
+        Ok(Some(update)) => Ok(UpdateInfo::from_update(&update)),
+        Ok(None) => Ok(UpdateInfo::up_to_date()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[tauri::command]
+async fn install_update(app: AppHandle) -> Result<(), String> {
+    let updater = app.updater().map_err(|e| e.to_string())?;
+    let update = updater.check().await.map_err(|e| e.to_string())?;
+    let Some(update) = update else {
+        return Err("no update available".into());
+    };
+
+    let progress_handle = app.clone();
+    update
+        .download_and_install(
+            move |chunk_length, content_length| {
+                let _ = progress_handle.emit(
+                    "update-download-progress",
+                    json!({
+                        "chunkLength": chunk_length,
+                        "contentLength": content_length,
+                    }),
+                );
+            },
+            || {
+                let _ = app.emit("update-installed", ());
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+fn parse_shortcut(accelerator: &str) -> Result<Shortcut, String> {
+    accelerator
+        .parse()
+        .map_err(|_| format!("invalid accelerator: {accelerator}"))
+}
+
+fn unregister_if_bound(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let shortcut = parse_shortcut(accelerator)?;
+    let manager = app.global_shortcut();
+    if manager.is_registered(shortcut) {
+        manager.unregister(shortcut).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn bind_generate_shortcut(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let shortcut = parse_shortcut(accelerator)?;
+    let manager = app.global_shortcut();
+    if manager.is_registered(shortcut) {
+        manager.unregister(shortcut).map_err(|e| e.to_string())?;
+    }
+
+    let handle = app.clone();
+    manager
+        .on_shortcut(shortcut, move |_app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                let _ = handle.emit("generate-requested", ());
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn register_generate_shortcut(app: AppHandle, accelerator: String) -> Result<(), String> {
+    let mut cfg = config::load(&app);
+    if let Some(previous) = cfg.generate_shortcut.as_deref() {
+        if previous != accelerator {
+            unregister_if_bound(&app, previous)?;
+        }
+    }
+
+    bind_generate_shortcut(&app, &accelerator)?;
+
+    cfg.generate_shortcut = Some(accelerator);
+    config::save(&app, &cfg)
+}
+
+#[tauri::command]
+fn unregister_generate_shortcut(app: AppHandle) -> Result<(), String> {
+    let mut cfg = config::load(&app);
+    if let Some(accelerator) = cfg.generate_shortcut.as_deref() {
+        unregister_if_bound(&app, accelerator)?;
+    }
+
+    cfg.generate_shortcut = None;
+    config::save(&app, &cfg)
+}
+
+const TRAY_ICON_ID: &str = "main-tray";
+
+fn build_tray(app: &AppHandle) -> tauri::Result<()> {
+    let generate = MenuItem::with_id(app, "generate", "Generate Resume", true, None::<&str>)?;
+    let open_output =
+        MenuItem::with_id(app, "open_output", "Open Output Folder", true, None::<&str>)?;
+    let check_health =
+        MenuItem::with_id(app, "check_health", "Check API Health", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let separator = PredefinedMenuItem::separator(app)?;
+    let menu = Menu::with_items(
+        app,
+        &[&generate, &open_output, &check_health, &separator, &quit],
+    )?;
+
+    let icon = app
+        .default_window_icon()
+        .cloned()
+        .expect("default window icon is configured in tauri.conf.json");
+
+    TrayIconBuilder::with_id(TRAY_ICON_ID)
+        .icon(icon)
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(handle_tray_menu_event)
+        .on_tray_icon_event(handle_tray_icon_event)
+        .build(app)?;
+
+    Ok(())
+}
+
+fn handle_tray_icon_event(tray: &tauri::tray::TrayIcon, event: TrayIconEvent) {
+    if let TrayIconEvent::Click {
+        button: MouseButton::Left,
+        button_state: MouseButtonState::Up,
+        ..
+    } = event
+    {
+        let app = tray.app_handle();
+        if let Some(window) = app.get_webview_window("main") {
+            if window.is_visible().unwrap_or(false) {
+                let _ = window.hide();
+            } else {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }
+    }
+}
+
+fn handle_tray_menu_event(app: &AppHandle, event: MenuEvent) {
+    match event.id().as_ref() {
+        "generate" => {
+            let _ = app.emit("tray-generate-requested", ());
+        }
+        "open_output" => {
+            let _ = app.emit("tray-open-output-requested", ());
+        }
+        "check_health" => {
+            let _ = app.emit("tray-check-health-requested", ());
+        }
+        "quit" => {
+            app.exit(0);
+        }
+        _ => {}
+    }
+}
+
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .setup(|app| {
+            build_tray(&app.handle())?;
+
+            let handle = app.handle();
+            tauri::async_runtime::spawn(async move {
+                if let Ok(updater) = handle.updater() {
+                    if let Ok(Some(update)) = updater.check().await {
+                        let _ = handle.emit("update-available", UpdateInfo::from_update(&update));
+                    }
+                }
+            });
+
+            let handle = app.handle();
+            let accelerator = config::load(&handle)
+                .generate_shortcut
+                .unwrap_or_else(|| DEFAULT_GENERATE_SHORTCUT.to_string());
+            if let Err(e) = bind_generate_shortcut(&handle, &accelerator) {
+                eprintln!("failed to register generate shortcut: {e}");
+            }
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             check_api_health,
             open_output_folder,
-            get_platform_info
+            get_platform_info,
+            set_tray_status,
+            check_for_update,
+            install_update,
+            get_backend_url,
+            set_backend_url,
+            register_generate_shortcut,
+            unregister_generate_shortcut,
+            reveal_in_file_manager,
+            copy_resume_to_clipboard,
+            notify_export_complete
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");